@@ -0,0 +1,154 @@
+//! Headless benchmark for [Display::fill_solid](mipidsi::Display::fill_solid).
+//!
+//! Drives a real `Display<_, ST7796<_>, _>` over a transaction-counting mock interface through a
+//! bouncing-logo animation, clearing the frame every tick once through the whole-area
+//! `DrawTarget::clear` (which calls the `fill_solid` fast path) and once by feeding the same
+//! pixels through `DrawTarget::draw_iter` one at a time (the path a per-pixel fill takes, since
+//! each single-pixel `Pixel` sets its own one-pixel address window). Reports the interface
+//! transaction and byte counts for both, to make the savings from the address-window fast path
+//! visible. Run with:
+//!
+//! ```sh
+//! cargo run --example bounce_fill_bench
+//! ```
+
+use std::{cell::RefCell, rc::Rc};
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::{Rgb565, RgbColor},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+use embedded_hal::{
+    delay::DelayNs,
+    digital::{ErrorType, OutputPin},
+};
+use mipidsi::{size::DisplaySize240x320, Builder};
+
+const FRAMES: u32 = 120;
+const LOGO_SIZE: Size = Size::new(32, 32);
+
+fn main() {
+    let fast = run(FRAMES, Clear::WholeArea);
+    let naive = run(FRAMES, Clear::PerPixel);
+
+    println!("fast clear  (fill_solid):  {fast:?}");
+    println!("naive clear (per-pixel):   {naive:?}");
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Transactions {
+    commands: usize,
+    bytes: usize,
+}
+
+#[derive(Clone, Copy)]
+enum Clear {
+    /// One [Display::fill_solid](mipidsi::Display::fill_solid) call over the whole frame.
+    WholeArea,
+    /// The frame cleared one `Pixel` at a time through `DrawTarget::draw_iter`.
+    PerPixel,
+}
+
+fn run(frames: u32, clear: Clear) -> Transactions {
+    let stats = Rc::new(RefCell::new(Transactions::default()));
+    let interface = CountingInterface {
+        stats: stats.clone(),
+    };
+
+    let mut display = Builder::st7796(interface, DisplaySize240x320)
+        .init(&mut NoDelay, Some(NoPin))
+        .expect("mock init never fails");
+
+    // Only count steady-state frame traffic, not one-time init.
+    *stats.borrow_mut() = Transactions::default();
+
+    let bounds = display.bounding_box();
+    let mut position = Point::zero();
+    let mut velocity = Point::new(3, 2);
+
+    for _ in 0..frames {
+        match clear {
+            Clear::WholeArea => display.clear(Rgb565::BLACK),
+            Clear::PerPixel => display.draw_iter(
+                bounds
+                    .points()
+                    .map(|point| Pixel(point, Rgb565::BLACK)),
+            ),
+        }
+        .expect("mock interface never errors");
+
+        display
+            .fill_solid(&Rectangle::new(position, LOGO_SIZE), Rgb565::RED)
+            .expect("mock interface never errors");
+
+        position += velocity;
+        if position.x <= 0 || position.x + LOGO_SIZE.width as i32 >= bounds.size.width as i32 {
+            velocity.x = -velocity.x;
+        }
+        if position.y <= 0 || position.y + LOGO_SIZE.height as i32 >= bounds.size.height as i32 {
+            velocity.y = -velocity.y;
+        }
+    }
+
+    let totals = *stats.borrow();
+    totals
+}
+
+/// A [WriteOnlyDataCommand] that counts transactions and bytes instead of talking to hardware.
+struct CountingInterface {
+    stats: Rc<RefCell<Transactions>>,
+}
+
+impl CountingInterface {
+    fn record(&mut self, data: DataFormat<'_>) {
+        let mut stats = self.stats.borrow_mut();
+        stats.commands += 1;
+        stats.bytes += match data {
+            DataFormat::U8(s) => s.len(),
+            DataFormat::U16BE(s) | DataFormat::U16LE(s) => s.len() * 2,
+            DataFormat::U8Iter(i) => i.count(),
+            DataFormat::U16BEIter(i) | DataFormat::U16LEIter(i) => i.count() * 2,
+            _ => 0,
+        };
+    }
+}
+
+impl WriteOnlyDataCommand for CountingInterface {
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.record(cmd);
+        Ok(())
+    }
+
+    fn send_data(&mut self, data: DataFormat<'_>) -> Result<(), DisplayError> {
+        self.record(data);
+        Ok(())
+    }
+}
+
+/// No-op delay; the benchmark only cares about interface traffic, not real timing.
+struct NoDelay;
+
+impl DelayNs for NoDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// No-op reset pin; the model's hard reset pulses it without effect on the mock interface.
+struct NoPin;
+
+impl ErrorType for NoPin {
+    type Error = core::convert::Infallible;
+}
+
+impl OutputPin for NoPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+//! Buffered rendering, enabled via the `buffered` crate feature.
+//!
+//! Instead of streaming every primitive straight over the display interface, [BufferedDisplay]
+//! wraps a [Display] and a caller-owned [DisplayBuffer], recording draws into that in-RAM
+//! framebuffer and only pushing the dirty region to the panel when [flush](BufferedDisplay::flush)
+//! is called. This mirrors the dual direct/buffered rendering modes of the st7567s driver and
+//! avoids one SPI transaction per primitive, which is the dominant cost for animated UIs.
+
+use display_interface::{DataFormat, WriteOnlyDataCommand};
+use embedded_graphics_core::{
+    pixelcolor::IntoStorage,
+    prelude::{Point, Size},
+    primitives::Rectangle,
+};
+use embedded_hal::digital::OutputPin;
+
+use crate::{
+    dcs::{SetColumnAddress, SetPageAddress, WriteMemoryStart},
+    models::Model,
+    Display, Error,
+};
+
+/// Backing storage for a [BufferedDisplay]'s framebuffer.
+///
+/// This is implemented for `&mut [C]` so a caller-provided slice (e.g. backed by a
+/// `[Rgb565; W * H]` array) can be used without requiring an allocator.
+pub trait DisplayBuffer<C> {
+    /// Returns the pixel storage as a flat, row-major slice.
+    fn pixels(&mut self) -> &mut [C];
+}
+
+impl<C> DisplayBuffer<C> for &mut [C] {
+    fn pixels(&mut self) -> &mut [C] {
+        self
+    }
+}
+
+/// Wraps a [Display] to provide a buffered rendering mode, see the [module](self) documentation.
+pub struct BufferedDisplay<DI, MODEL, RST, BUF>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+    BUF: DisplayBuffer<MODEL::ColorFormat>,
+{
+    display: Display<DI, MODEL, RST>,
+    buffer: BUF,
+    width: u32,
+    height: u32,
+    dirty: Option<Rectangle>,
+}
+
+impl<DI, MODEL, RST, BUF> BufferedDisplay<DI, MODEL, RST, BUF>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+    BUF: DisplayBuffer<MODEL::ColorFormat>,
+    MODEL::ColorFormat: Copy + IntoStorage<Storage = u16>,
+{
+    /// Wraps `display`, rendering into `buffer` (row-major, `width * height` pixels) until
+    /// [flush](Self::flush) is called.
+    pub fn new(display: Display<DI, MODEL, RST>, buffer: BUF, width: u32, height: u32) -> Self {
+        Self {
+            display,
+            buffer,
+            width,
+            height,
+            dirty: None,
+        }
+    }
+
+    /// Sets a single pixel in the framebuffer, marking it dirty.
+    pub fn set_pixel(&mut self, point: Point, color: MODEL::ColorFormat) {
+        if point.x < 0 || point.y < 0 || point.x as u32 >= self.width || point.y as u32 >= self.height
+        {
+            return;
+        }
+
+        let index = point.y as u32 * self.width + point.x as u32;
+        self.buffer.pixels()[index as usize] = color;
+
+        self.mark_dirty(Rectangle::new(point, Size::new(1, 1)));
+    }
+
+    /// Fills the whole framebuffer with `color`, marking the whole display dirty.
+    pub fn clear(&mut self, color: MODEL::ColorFormat) {
+        self.buffer.pixels().fill(color);
+        self.mark_dirty(Rectangle::new(Point::zero(), Size::new(self.width, self.height)));
+    }
+
+    fn mark_dirty(&mut self, area: Rectangle) {
+        self.dirty = Some(match self.dirty {
+            Some(dirty) => union(dirty, area),
+            None => area,
+        });
+    }
+
+    /// Pushes the dirty region of the framebuffer to the panel in a single address-window burst,
+    /// then clears the dirty tracking. Does nothing if nothing has changed since the last flush.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let Some(dirty) = self.dirty.take() else {
+            return Ok(());
+        };
+
+        let x0 = dirty.top_left.x as u16;
+        let y0 = dirty.top_left.y as u16;
+        let x1 = x0 + dirty.size.width as u16 - 1;
+        let y1 = y0 + dirty.size.height as u16 - 1;
+
+        let (ox, oy) = self.display.options.window_offset();
+        let dcs = &mut self.display.dcs;
+        dcs.write_command(SetColumnAddress::new(ox + x0, ox + x1))?;
+        dcs.write_command(SetPageAddress::new(oy + y0, oy + y1))?;
+        dcs.write_command(WriteMemoryStart)?;
+
+        for row in y0..=y1 {
+            let start = row as u32 * self.width + x0 as u32;
+            let end = start + dirty.size.width;
+            let mut iter = self.buffer.pixels()[start as usize..end as usize]
+                .iter()
+                .map(|c| (*c).into_storage());
+            dcs.di.send_data(DataFormat::U16BEIter(&mut iter))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Smallest [Rectangle] containing both `a` and `b`.
+fn union(a: Rectangle, b: Rectangle) -> Rectangle {
+    let left = a.top_left.x.min(b.top_left.x);
+    let top = a.top_left.y.min(b.top_left.y);
+    let right = (a.top_left.x + a.size.width as i32).max(b.top_left.x + b.size.width as i32);
+    let bottom = (a.top_left.y + a.size.height as i32).max(b.top_left.y + b.size.height as i32);
+
+    Rectangle::new(
+        Point::new(left, top),
+        Size::new((right - left) as u32, (bottom - top) as u32),
+    )
+}
+
+impl<DI, MODEL, RST, BUF> embedded_graphics_core::geometry::OriginDimensions
+    for BufferedDisplay<DI, MODEL, RST, BUF>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+    BUF: DisplayBuffer<MODEL::ColorFormat>,
+{
+    fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+}
+
+impl<DI, MODEL, RST, BUF> embedded_graphics_core::draw_target::DrawTarget
+    for BufferedDisplay<DI, MODEL, RST, BUF>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+    BUF: DisplayBuffer<MODEL::ColorFormat>,
+    MODEL::ColorFormat: Copy + IntoStorage<Storage = u16>,
+{
+    type Color = MODEL::ColorFormat;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = embedded_graphics_core::Pixel<Self::Color>>,
+    {
+        for embedded_graphics_core::Pixel(point, color) in pixels {
+            self.set_pixel(point, color);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_of_disjoint_rectangles_covers_both() {
+        let a = Rectangle::new(Point::new(0, 0), Size::new(10, 10));
+        let b = Rectangle::new(Point::new(20, 30), Size::new(5, 5));
+
+        assert_eq!(
+            union(a, b),
+            Rectangle::new(Point::new(0, 0), Size::new(25, 35))
+        );
+    }
+
+    #[test]
+    fn union_with_contained_rectangle_is_the_outer_one() {
+        let outer = Rectangle::new(Point::new(0, 0), Size::new(100, 100));
+        let inner = Rectangle::new(Point::new(10, 10), Size::new(5, 5));
+
+        assert_eq!(union(outer, inner), outer);
+        assert_eq!(union(inner, outer), outer);
+    }
+
+    #[test]
+    fn union_is_independent_of_argument_order() {
+        let a = Rectangle::new(Point::new(5, -5), Size::new(10, 20));
+        let b = Rectangle::new(Point::new(-5, 5), Size::new(20, 10));
+
+        assert_eq!(union(a, b), union(b, a));
+    }
+}
@@ -0,0 +1,67 @@
+use display_interface::WriteOnlyDataCommand;
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+use crate::{
+    dcs::{Dcs, FrameRate},
+    error::InitError,
+    models::Model,
+    Display, ModelOptions,
+};
+
+/// Builder for [Display].
+pub struct Builder<DI, MODEL>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+{
+    di: DI,
+    model: MODEL,
+    options: ModelOptions,
+}
+
+impl<DI, MODEL> Builder<DI, MODEL>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+{
+    /// Creates a new builder for the given display interface and model, using the model's
+    /// default options.
+    pub fn with_model(di: DI, model: MODEL) -> Self {
+        Self {
+            di,
+            options: MODEL::default_options(),
+            model,
+        }
+    }
+
+    /// Overrides the model's default frame rate, applied during [init](Self::init).
+    pub fn with_frame_rate(mut self, frame_rate: FrameRate) -> Self {
+        self.options = self.options.with_frame_rate(frame_rate);
+        self
+    }
+
+    /// Initializes the display by calling [Model::init], consuming the builder and returning a
+    /// ready to use [Display].
+    pub fn init<RST, DELAY>(
+        self,
+        delay: &mut DELAY,
+        mut rst: Option<RST>,
+    ) -> Result<Display<DI, MODEL, RST>, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+    {
+        let mut dcs = Dcs::write_only(self.di);
+        let mut model = self.model;
+
+        let madctl = model.init(&mut dcs, delay, &self.options, &mut rst)?;
+
+        Ok(Display {
+            dcs,
+            model,
+            rst,
+            options: self.options,
+            madctl,
+        })
+    }
+}
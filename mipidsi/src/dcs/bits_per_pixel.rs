@@ -0,0 +1,47 @@
+use embedded_graphics_core::pixelcolor::{Rgb565, Rgb666};
+
+/// Number of bits used for a single pixel's color value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BitsPerPixel {
+    /// 3 bits, RGB111
+    Three = 0b001,
+    /// 8 bits, RGB332
+    Eight = 0b010,
+    /// 12 bits, RGB444
+    Twelve = 0b011,
+    /// 16 bits, RGB565
+    Sixteen = 0b101,
+    /// 18 bits, RGB666
+    EighteenBit = 0b110,
+    /// 24 bits, RGB888
+    TwentyFour = 0b111,
+}
+
+impl BitsPerPixel {
+    /// Returns the [BitsPerPixel] value matching a given color type `C`.
+    pub const fn from_rgb_color<C>() -> Self
+    where
+        C: embedded_graphics_core::pixelcolor::PixelColor,
+    {
+        // the color types used by this crate only carry Rgb565 or Rgb666 data
+        if core::mem::size_of::<C>() == core::mem::size_of::<Rgb565>() {
+            Self::Sixteen
+        } else if core::mem::size_of::<C>() == core::mem::size_of::<Rgb666>() {
+            Self::EighteenBit
+        } else {
+            Self::TwentyFour
+        }
+    }
+
+    /// Returns the number of bits per pixel.
+    pub const fn bpp(self) -> u32 {
+        match self {
+            Self::Three => 3,
+            Self::Eight => 8,
+            Self::Twelve => 12,
+            Self::Sixteen => 16,
+            Self::EighteenBit => 18,
+            Self::TwentyFour => 24,
+        }
+    }
+}
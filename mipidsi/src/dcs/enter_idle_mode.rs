@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Enter Idle Mode
+pub struct EnterIdleMode;
+
+impl DcsCommand for EnterIdleMode {
+    fn instruction(&self) -> u8 {
+        0x39
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Enter Normal Mode
+pub struct EnterNormalMode;
+
+impl DcsCommand for EnterNormalMode {
+    fn instruction(&self) -> u8 {
+        0x13
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Enter Partial Mode
+pub struct EnterPartialMode;
+
+impl DcsCommand for EnterPartialMode {
+    fn instruction(&self) -> u8 {
+        0x12
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
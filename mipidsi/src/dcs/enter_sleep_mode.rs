@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Enter Sleep Mode (Sleep In)
+pub struct EnterSleepMode;
+
+impl DcsCommand for EnterSleepMode {
+    fn instruction(&self) -> u8 {
+        0x10
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
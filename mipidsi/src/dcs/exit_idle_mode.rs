@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Exit Idle Mode
+pub struct ExitIdleMode;
+
+impl DcsCommand for ExitIdleMode {
+    fn instruction(&self) -> u8 {
+        0x38
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Exit Sleep Mode (Sleep Out)
+pub struct ExitSleepMode;
+
+impl DcsCommand for ExitSleepMode {
+    fn instruction(&self) -> u8 {
+        0x11
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
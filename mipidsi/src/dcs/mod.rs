@@ -0,0 +1,106 @@
+//! MIPI DCS commands.
+//!
+//! This module contains structs for the MIPI DCS (Display Command Set) commands used by
+//! [Model](crate::models::Model) implementations, as well as the [Dcs] helper used to send
+//! them over a [WriteOnlyDataCommand].
+
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+
+mod bits_per_pixel;
+mod enter_idle_mode;
+mod enter_normal_mode;
+mod enter_partial_mode;
+mod enter_sleep_mode;
+mod exit_idle_mode;
+mod exit_sleep_mode;
+mod pixel_format;
+mod set_address_mode;
+mod set_cabc;
+mod set_column_address;
+mod set_ctrl_display;
+mod set_display_brightness;
+mod set_display_on;
+mod set_frame_rate;
+mod set_invert_mode;
+mod set_page_address;
+mod set_partial_area;
+mod set_pixel_format;
+mod set_scroll_area;
+mod soft_reset;
+mod write_memory_start;
+
+pub use bits_per_pixel::BitsPerPixel;
+pub use enter_idle_mode::EnterIdleMode;
+pub use enter_normal_mode::EnterNormalMode;
+pub use enter_partial_mode::EnterPartialMode;
+pub use enter_sleep_mode::EnterSleepMode;
+pub use exit_idle_mode::ExitIdleMode;
+pub use exit_sleep_mode::ExitSleepMode;
+pub use pixel_format::PixelFormat;
+pub use set_address_mode::SetAddressMode;
+pub use set_cabc::{Cabc, WriteCabc};
+pub use set_column_address::SetColumnAddress;
+pub use set_ctrl_display::WriteCtrlDisplay;
+pub use set_display_brightness::WriteDisplayBrightness;
+pub use set_display_on::SetDisplayOn;
+pub use set_frame_rate::{
+    write_frame_rate, FrameRate, FrameRateControlIdle, FrameRateControlNormal,
+    FrameRateControlPartial,
+};
+pub use set_invert_mode::SetInvertMode;
+pub use set_page_address::SetPageAddress;
+pub use set_partial_area::SetPartialArea;
+pub use set_pixel_format::SetPixelFormat;
+pub use set_scroll_area::SetScrollArea;
+pub use soft_reset::SoftReset;
+pub use write_memory_start::WriteMemoryStart;
+
+/// Wrapper around a [WriteOnlyDataCommand] used to send [DcsCommand]s.
+pub struct Dcs<DI> {
+    /// DI
+    pub di: DI,
+}
+
+impl<DI> Dcs<DI> {
+    /// Creates a new [Dcs] wrapping the given [WriteOnlyDataCommand].
+    pub fn write_only(di: DI) -> Self {
+        Self { di }
+    }
+}
+
+impl<DI> Dcs<DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Writes a DCS command to the display interface.
+    pub fn write_command(&mut self, command: impl DcsCommand) -> Result<(), DisplayError> {
+        let mut param_bytes: [u8; 16] = [0; 16];
+        let n = command.fill_params_buf(&mut param_bytes);
+
+        self.write_raw(command.instruction(), &param_bytes[..n])
+    }
+
+    /// Writes a raw instruction with the given parameter bytes to the display interface.
+    pub fn write_raw(&mut self, instruction: u8, param_bytes: &[u8]) -> Result<(), DisplayError> {
+        self.di.send_commands(DataFormat::U8(&[instruction]))?;
+
+        if !param_bytes.is_empty() {
+            self.di.send_data(DataFormat::U8(param_bytes))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Common trait for DCS commands.
+///
+/// The controller-specific opcode and parameter bytes are provided by implementors and sent
+/// through [Dcs::write_command].
+pub trait DcsCommand {
+    /// Returns the instruction (opcode) for this command.
+    fn instruction(&self) -> u8;
+
+    /// Fills the given buffer with this command's parameter bytes, returning the number of
+    /// bytes written.
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize;
+}
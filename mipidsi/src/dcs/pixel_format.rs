@@ -0,0 +1,33 @@
+use super::BitsPerPixel;
+
+/// Pixel format used on the RGB and MCU (DBI) interfaces.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PixelFormat {
+    dpi: BitsPerPixel,
+    dbi: BitsPerPixel,
+}
+
+impl PixelFormat {
+    /// Creates a [PixelFormat] using the same [BitsPerPixel] for both interfaces.
+    pub const fn with_all(bpp: BitsPerPixel) -> Self {
+        Self { dpi: bpp, dbi: bpp }
+    }
+
+    pub(crate) const fn as_u8(self) -> u8 {
+        (self.dpi as u8) << 4 | (self.dbi as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_the_same_bpp_into_both_nibbles() {
+        let format = PixelFormat::with_all(BitsPerPixel::Sixteen);
+        assert_eq!(
+            format.as_u8(),
+            (BitsPerPixel::Sixteen as u8) << 4 | BitsPerPixel::Sixteen as u8
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use super::DcsCommand;
+use crate::{ModelOptions, Orientation};
+
+/// Set Address Mode (MADCTL)
+///
+/// Controls the row/column exchange, the row/column/refresh order and the color order used by
+/// the panel's GRAM addressing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SetAddressMode(u8);
+
+impl SetAddressMode {
+    const ROW_ADDRESS_ORDER: u8 = 0b1000_0000;
+    const COLUMN_ADDRESS_ORDER: u8 = 0b0100_0000;
+    const ROW_COLUMN_EXCHANGE: u8 = 0b0010_0000;
+    const VERTICAL_REFRESH_ORDER: u8 = 0b0001_0000;
+    const HORIZONTAL_REFRESH_ORDER: u8 = 0b0000_0100;
+
+    /// Creates a [SetAddressMode] from the given orientation and mirror settings.
+    pub fn new(
+        orientation: Orientation,
+        invert_vertical_refresh: bool,
+        invert_horizontal_refresh: bool,
+    ) -> Self {
+        // A row/column exchange on its own transposes the image, so the landscape rotations pair
+        // it with exactly one mirror bit.
+        let mut value = match orientation {
+            Orientation::Portrait => 0,
+            Orientation::PortraitInverted => Self::ROW_ADDRESS_ORDER | Self::COLUMN_ADDRESS_ORDER,
+            Orientation::Landscape => Self::ROW_COLUMN_EXCHANGE | Self::COLUMN_ADDRESS_ORDER,
+            Orientation::LandscapeInverted => Self::ROW_COLUMN_EXCHANGE | Self::ROW_ADDRESS_ORDER,
+        };
+
+        if invert_vertical_refresh {
+            value |= Self::VERTICAL_REFRESH_ORDER;
+        }
+
+        if invert_horizontal_refresh {
+            value |= Self::HORIZONTAL_REFRESH_ORDER;
+        }
+
+        Self(value)
+    }
+}
+
+impl From<&ModelOptions> for SetAddressMode {
+    fn from(options: &ModelOptions) -> Self {
+        Self::new(
+            options.orientation,
+            options.invert_vertical_refresh,
+            options.invert_horizontal_refresh,
+        )
+    }
+}
+
+impl DcsCommand for SetAddressMode {
+    fn instruction(&self) -> u8 {
+        0x36
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0] = self.0;
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portrait_sets_no_bits() {
+        assert_eq!(SetAddressMode::new(Orientation::Portrait, false, false).0, 0);
+    }
+
+    #[test]
+    fn portrait_inverted_sets_my_and_mx() {
+        assert_eq!(
+            SetAddressMode::new(Orientation::PortraitInverted, false, false).0,
+            SetAddressMode::ROW_ADDRESS_ORDER | SetAddressMode::COLUMN_ADDRESS_ORDER
+        );
+    }
+
+    #[test]
+    fn landscape_sets_mv_mx() {
+        assert_eq!(
+            SetAddressMode::new(Orientation::Landscape, false, false).0,
+            SetAddressMode::ROW_COLUMN_EXCHANGE | SetAddressMode::COLUMN_ADDRESS_ORDER
+        );
+    }
+
+    #[test]
+    fn landscape_inverted_sets_mv_my() {
+        assert_eq!(
+            SetAddressMode::new(Orientation::LandscapeInverted, false, false).0,
+            SetAddressMode::ROW_COLUMN_EXCHANGE | SetAddressMode::ROW_ADDRESS_ORDER
+        );
+    }
+
+    #[test]
+    fn refresh_inverts_are_independent_of_orientation() {
+        assert_eq!(
+            SetAddressMode::new(Orientation::Portrait, true, true).0,
+            SetAddressMode::VERTICAL_REFRESH_ORDER | SetAddressMode::HORIZONTAL_REFRESH_ORDER
+        );
+    }
+}
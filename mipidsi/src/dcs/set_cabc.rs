@@ -0,0 +1,29 @@
+use super::DcsCommand;
+
+/// Content Adaptive Brightness Control mode.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Cabc {
+    /// CABC is disabled.
+    #[default]
+    Off = 0b00,
+    /// CABC optimized for user interface images.
+    UserInterface = 0b01,
+    /// CABC optimized for still images.
+    StillPicture = 0b10,
+    /// CABC optimized for moving images.
+    MovingImage = 0b11,
+}
+
+/// Write Content Adaptive Brightness Control
+pub struct WriteCabc(pub Cabc);
+
+impl DcsCommand for WriteCabc {
+    fn instruction(&self) -> u8 {
+        0x55
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0] = self.0 as u8;
+        1
+    }
+}
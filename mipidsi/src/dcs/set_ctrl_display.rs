@@ -0,0 +1,88 @@
+use super::DcsCommand;
+
+/// Write Control Display
+///
+/// Enables brightness (`BCTRL`), display dimming (`DD`) and backlight (`BL`) control.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WriteCtrlDisplay {
+    brightness_control: bool,
+    dimming: bool,
+    backlight: bool,
+}
+
+impl WriteCtrlDisplay {
+    /// Creates a new, all-off [WriteCtrlDisplay] command.
+    pub const fn new() -> Self {
+        Self {
+            brightness_control: false,
+            dimming: false,
+            backlight: false,
+        }
+    }
+
+    /// Enables/disables brightness control (`BCTRL`), i.e. whether
+    /// [WriteDisplayBrightness](super::WriteDisplayBrightness) has any effect.
+    pub const fn with_brightness_control(mut self, enabled: bool) -> Self {
+        self.brightness_control = enabled;
+        self
+    }
+
+    /// Enables/disables display dimming (`DD`).
+    pub const fn with_dimming(mut self, enabled: bool) -> Self {
+        self.dimming = enabled;
+        self
+    }
+
+    /// Enables/disables the backlight (`BL`).
+    pub const fn with_backlight(mut self, enabled: bool) -> Self {
+        self.backlight = enabled;
+        self
+    }
+}
+
+impl DcsCommand for WriteCtrlDisplay {
+    fn instruction(&self) -> u8 {
+        0x53
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        let mut value = 0;
+
+        if self.backlight {
+            value |= 0b0000_0100; // BL
+        }
+        if self.dimming {
+            value |= 0b0000_1000; // DD
+        }
+        if self.brightness_control {
+            value |= 0b0010_0000; // BCTRL
+        }
+
+        buffer[0] = value;
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brightness_and_backlight_match_old_raw_write() {
+        let mut buffer = [0u8; 16];
+        let n = WriteCtrlDisplay::new()
+            .with_brightness_control(true)
+            .with_backlight(true)
+            .fill_params_buf(&mut buffer);
+
+        assert_eq!(n, 1);
+        assert_eq!(buffer[0], 0x24);
+    }
+
+    #[test]
+    fn all_off_by_default() {
+        let mut buffer = [0u8; 16];
+        WriteCtrlDisplay::new().fill_params_buf(&mut buffer);
+        assert_eq!(buffer[0], 0x00);
+    }
+}
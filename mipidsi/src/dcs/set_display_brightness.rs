@@ -0,0 +1,18 @@
+use super::DcsCommand;
+
+/// Write Display Brightness
+///
+/// Sets the brightness value of the display, from `0x00` (darkest) to `0xFF` (brightest). Has
+/// no effect unless brightness control is enabled, see [WriteCtrlDisplay](super::WriteCtrlDisplay).
+pub struct WriteDisplayBrightness(pub u8);
+
+impl DcsCommand for WriteDisplayBrightness {
+    fn instruction(&self) -> u8 {
+        0x51
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0] = self.0;
+        1
+    }
+}
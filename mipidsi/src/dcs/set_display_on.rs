@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Set Display On
+pub struct SetDisplayOn;
+
+impl DcsCommand for SetDisplayOn {
+    fn instruction(&self) -> u8 {
+        0x29
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
@@ -0,0 +1,108 @@
+use display_interface::{DisplayError, WriteOnlyDataCommand};
+
+use super::{Dcs, DcsCommand};
+
+/// Frame rate parameters shared by the normal, idle and partial mode frame-rate-control
+/// registers ([FrameRateControlNormal]/[FrameRateControlIdle]/[FrameRateControlPartial]).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FrameRate {
+    /// Frame rate divisor (`RTNA`, 5 bits). Lower values raise the refresh rate.
+    pub rtna: u8,
+    /// Front porch setting, in line-time units.
+    pub front_porch: u8,
+    /// Back porch setting, in line-time units.
+    pub back_porch: u8,
+}
+
+impl FrameRate {
+    /// Creates a new [FrameRate] from the given `RTNA` divisor and porch settings.
+    ///
+    /// `rtna` only has 5 usable bits; any higher bits are masked off rather than rejected, since
+    /// this is used by `const` callers that can't handle a `Result`.
+    pub const fn new(rtna: u8, front_porch: u8, back_porch: u8) -> Self {
+        Self {
+            rtna: rtna & 0x1F,
+            front_porch,
+            back_porch,
+        }
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0] = self.rtna;
+        buffer[1] = self.front_porch;
+        buffer[2] = self.back_porch;
+        3
+    }
+}
+
+/// Frame Rate Control (In Normal Mode / Full Colors)
+pub struct FrameRateControlNormal(pub FrameRate);
+
+impl DcsCommand for FrameRateControlNormal {
+    fn instruction(&self) -> u8 {
+        0xB1
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        self.0.fill_params_buf(buffer)
+    }
+}
+
+/// Frame Rate Control (In Idle Mode / 8 Colors)
+pub struct FrameRateControlIdle(pub FrameRate);
+
+impl DcsCommand for FrameRateControlIdle {
+    fn instruction(&self) -> u8 {
+        0xB2
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        self.0.fill_params_buf(buffer)
+    }
+}
+
+/// Frame Rate Control (In Partial Mode / Full Colors)
+pub struct FrameRateControlPartial(pub FrameRate);
+
+impl DcsCommand for FrameRateControlPartial {
+    fn instruction(&self) -> u8 {
+        0xB3
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        self.0.fill_params_buf(buffer)
+    }
+}
+
+/// Writes `frame_rate` to the normal, idle and partial mode frame-rate-control registers.
+///
+/// Shared by [Display::set_frame_rate](crate::Display::set_frame_rate) and model `init`
+/// implementations that accept a [FrameRate] override, so the three-register sequence only
+/// needs to be kept correct in one place.
+pub fn write_frame_rate<DI>(dcs: &mut Dcs<DI>, frame_rate: FrameRate) -> Result<(), DisplayError>
+where
+    DI: WriteOnlyDataCommand,
+{
+    dcs.write_command(FrameRateControlNormal(frame_rate))?;
+    dcs.write_command(FrameRateControlIdle(frame_rate))?;
+    dcs.write_command(FrameRateControlPartial(frame_rate))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rtna_is_masked_to_5_bits() {
+        let frame_rate = FrameRate::new(0xFF, 0x10, 0x13);
+        assert_eq!(frame_rate.rtna, 0x1F);
+        assert_eq!(frame_rate.front_porch, 0x10);
+        assert_eq!(frame_rate.back_porch, 0x13);
+    }
+
+    #[test]
+    fn in_range_rtna_is_unchanged() {
+        assert_eq!(FrameRate::new(0x0F, 0, 0).rtna, 0x0F);
+    }
+}
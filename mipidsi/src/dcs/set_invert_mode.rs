@@ -0,0 +1,18 @@
+use super::DcsCommand;
+
+/// Set Display Inversion Mode (Display Inversion On/Off)
+pub struct SetInvertMode(pub bool);
+
+impl DcsCommand for SetInvertMode {
+    fn instruction(&self) -> u8 {
+        if self.0 {
+            0x21
+        } else {
+            0x20
+        }
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
@@ -0,0 +1,29 @@
+use super::DcsCommand;
+
+/// Set Page Address (RASET)
+///
+/// Defines the page (y) address window, as an inclusive `start..=end` range, that subsequent
+/// [WriteMemoryStart](super::WriteMemoryStart) data is written into.
+pub struct SetPageAddress {
+    start: u16,
+    end: u16,
+}
+
+impl SetPageAddress {
+    /// Creates a new [SetPageAddress] command for the given inclusive row range.
+    pub const fn new(start: u16, end: u16) -> Self {
+        Self { start, end }
+    }
+}
+
+impl DcsCommand for SetPageAddress {
+    fn instruction(&self) -> u8 {
+        0x2B
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0..2].copy_from_slice(&self.start.to_be_bytes());
+        buffer[2..4].copy_from_slice(&self.end.to_be_bytes());
+        4
+    }
+}
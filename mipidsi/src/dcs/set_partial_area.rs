@@ -0,0 +1,29 @@
+use super::DcsCommand;
+
+/// Set Partial Area
+///
+/// Defines the partial display area, given as the start and end row (inclusive) of the frame
+/// memory that remains visible while in partial mode, see [EnterPartialMode](super::EnterPartialMode).
+pub struct SetPartialArea {
+    start_row: u16,
+    end_row: u16,
+}
+
+impl SetPartialArea {
+    /// Creates a new [SetPartialArea] command for the given (inclusive) row range.
+    pub const fn new(start_row: u16, end_row: u16) -> Self {
+        Self { start_row, end_row }
+    }
+}
+
+impl DcsCommand for SetPartialArea {
+    fn instruction(&self) -> u8 {
+        0x30
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0..2].copy_from_slice(&self.start_row.to_be_bytes());
+        buffer[2..4].copy_from_slice(&self.end_row.to_be_bytes());
+        4
+    }
+}
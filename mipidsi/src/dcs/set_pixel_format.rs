@@ -0,0 +1,22 @@
+use super::{DcsCommand, PixelFormat};
+
+/// Set Pixel Format
+pub struct SetPixelFormat(PixelFormat);
+
+impl SetPixelFormat {
+    /// Creates a new [SetPixelFormat] command for the given [PixelFormat].
+    pub const fn new(format: PixelFormat) -> Self {
+        Self(format)
+    }
+}
+
+impl DcsCommand for SetPixelFormat {
+    fn instruction(&self) -> u8 {
+        0x3A
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0] = self.0.as_u8();
+        1
+    }
+}
@@ -0,0 +1,49 @@
+use super::DcsCommand;
+use crate::ModelOptions;
+
+/// Set Scroll Area
+///
+/// Defines the vertical scrolling area via the top fixed area, the vertical scrolling area, and
+/// the bottom fixed area, given in number of lines from the top/bottom of the frame memory.
+pub struct SetScrollArea {
+    top_fixed_area: u16,
+    vertical_scrolling_area: u16,
+    bottom_fixed_area: u16,
+}
+
+impl SetScrollArea {
+    /// Creates a new [SetScrollArea] command from the given areas.
+    pub const fn new(
+        top_fixed_area: u16,
+        vertical_scrolling_area: u16,
+        bottom_fixed_area: u16,
+    ) -> Self {
+        Self {
+            top_fixed_area,
+            vertical_scrolling_area,
+            bottom_fixed_area,
+        }
+    }
+}
+
+impl From<&ModelOptions> for SetScrollArea {
+    fn from(options: &ModelOptions) -> Self {
+        let (_, fb_height) = options.framebuffer_size();
+        let (_, display_height) = options.display_size();
+
+        Self::new(0, fb_height.max(display_height), 0)
+    }
+}
+
+impl DcsCommand for SetScrollArea {
+    fn instruction(&self) -> u8 {
+        0x33
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> usize {
+        buffer[0..2].copy_from_slice(&self.top_fixed_area.to_be_bytes());
+        buffer[2..4].copy_from_slice(&self.vertical_scrolling_area.to_be_bytes());
+        buffer[4..6].copy_from_slice(&self.bottom_fixed_area.to_be_bytes());
+        6
+    }
+}
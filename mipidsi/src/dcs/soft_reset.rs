@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Software Reset
+pub struct SoftReset;
+
+impl DcsCommand for SoftReset {
+    fn instruction(&self) -> u8 {
+        0x01
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
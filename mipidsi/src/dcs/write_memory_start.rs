@@ -0,0 +1,14 @@
+use super::DcsCommand;
+
+/// Write Memory Start
+pub struct WriteMemoryStart;
+
+impl DcsCommand for WriteMemoryStart {
+    fn instruction(&self) -> u8 {
+        0x2C
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> usize {
+        0
+    }
+}
@@ -0,0 +1,224 @@
+use display_interface::{DataFormat, WriteOnlyDataCommand};
+use embedded_graphics_core::{pixelcolor::IntoStorage, primitives::Rectangle};
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+use crate::{
+    dcs::{
+        write_frame_rate, Cabc, Dcs, EnterIdleMode, EnterNormalMode, EnterPartialMode,
+        EnterSleepMode, ExitIdleMode, ExitSleepMode, FrameRate, SetAddressMode, SetColumnAddress,
+        SetPageAddress, SetPartialArea, WriteCabc, WriteCtrlDisplay, WriteDisplayBrightness,
+        WriteMemoryStart,
+    },
+    error::InitError,
+    models::Model,
+    Error, ModelOptions, Orientation,
+};
+
+/// A configured display, created using [Builder](crate::Builder).
+pub struct Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+{
+    pub(crate) dcs: Dcs<DI>,
+    pub(crate) model: MODEL,
+    pub(crate) rst: Option<RST>,
+    pub(crate) options: ModelOptions,
+    pub(crate) madctl: SetAddressMode,
+}
+
+impl<DI, MODEL, RST> Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+{
+    /// Writes the given pixel colors, starting from the current address window.
+    pub fn write_pixels<I>(&mut self, colors: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = MODEL::ColorFormat>,
+    {
+        self.model.write_pixels(&mut self.dcs, colors)
+    }
+
+    /// Sets the display brightness to `brightness`, from `0x00` (darkest) to `0xFF` (brightest).
+    ///
+    /// This first enables brightness control (`BCTRL`) and the backlight (`BL`) via
+    /// [WriteCtrlDisplay], so the new value takes effect immediately.
+    pub fn set_brightness(&mut self, brightness: u8) -> Result<(), Error> {
+        self.dcs.write_command(
+            WriteCtrlDisplay::new()
+                .with_brightness_control(true)
+                .with_backlight(true),
+        )?;
+        self.dcs
+            .write_command(WriteDisplayBrightness(brightness))?;
+        Ok(())
+    }
+
+    /// Sets the Content Adaptive Brightness Control (CABC) mode.
+    pub fn set_cabc(&mut self, cabc: Cabc) -> Result<(), Error> {
+        self.dcs.write_command(WriteCabc(cabc))?;
+        Ok(())
+    }
+
+    /// Puts the display into sleep mode.
+    ///
+    /// The panel stops refreshing and draws no power into its driving circuits, at the cost of
+    /// losing the frame memory contents on most controllers. Use [wake](Self::wake) to resume.
+    pub fn sleep<DELAY: DelayNs>(&mut self, delay: &mut DELAY) -> Result<(), Error> {
+        self.dcs.write_command(EnterSleepMode)?;
+        // Issuing further commands within 5ms of entering sleep mode can corrupt the display.
+        delay.delay_us(5_000);
+        Ok(())
+    }
+
+    /// Wakes the display from sleep mode, see [sleep](Self::sleep).
+    pub fn wake<DELAY: DelayNs>(&mut self, delay: &mut DELAY) -> Result<(), Error> {
+        self.dcs.write_command(ExitSleepMode)?;
+        // Drawing to the display within 120ms of sleep out risks SPI data issues.
+        delay.delay_us(120_000);
+        Ok(())
+    }
+
+    /// Pulses the reset pin supplied to [Builder::init](crate::Builder::init) and re-runs
+    /// [Model::init] to restore the panel to its configured state, e.g. after a brownout or a
+    /// wedged controller.
+    ///
+    /// Does nothing and returns `Ok(())` if no reset pin was supplied, since there is then
+    /// nothing to pulse and a soft reset could clobber state a caller is trying to recover.
+    pub fn hard_reset<DELAY: DelayNs>(
+        &mut self,
+        delay: &mut DELAY,
+    ) -> Result<(), InitError<RST::Error>> {
+        let Some(rst) = self.rst.as_mut() else {
+            return Ok(());
+        };
+
+        self.model.hard_reset(rst, delay)?;
+        self.madctl = self
+            .model
+            .init(&mut self.dcs, delay, &self.options, &mut self.rst)?;
+
+        Ok(())
+    }
+
+    /// Changes the display orientation at runtime, without re-running [Model::init].
+    ///
+    /// Recomputes and re-sends MADCTL for the new rotation/mirroring and updates the cached
+    /// framebuffer-to-panel mapping (see [size](embedded_graphics_core::geometry::OriginDimensions::size))
+    /// used by subsequent draws and address-window calculations.
+    pub fn set_orientation(&mut self, orientation: Orientation) -> Result<(), Error> {
+        self.options.orientation = orientation;
+
+        let madctl = SetAddressMode::from(&self.options);
+        self.dcs.write_command(madctl)?;
+        self.madctl = madctl;
+
+        Ok(())
+    }
+
+    /// Sets the frame rate used in normal, idle and partial mode.
+    ///
+    /// Lowering the rate while [idle](Self::set_idle) saves power; raising it suits
+    /// animation-heavy applications that need a snappier refresh.
+    pub fn set_frame_rate(&mut self, frame_rate: FrameRate) -> Result<(), Error> {
+        write_frame_rate(&mut self.dcs, frame_rate)?;
+        Ok(())
+    }
+
+    /// Restricts refresh and host access to the (inclusive) `start_row..=end_row` frame memory
+    /// range and enters partial mode, leaving the rest of the panel showing its last contents.
+    ///
+    /// Use [set_normal_mode](Self::set_normal_mode) to return to full-frame updates. Unlike
+    /// [sleep](Self::sleep)/[wake](Self::wake), entering or exiting partial mode carries no
+    /// documented settling time, so this does not take a `delay`.
+    pub fn set_partial_area(&mut self, start_row: u16, end_row: u16) -> Result<(), Error> {
+        self.dcs
+            .write_command(SetPartialArea::new(start_row, end_row))?;
+        self.dcs.write_command(EnterPartialMode)?;
+        Ok(())
+    }
+
+    /// Exits partial mode, see [set_partial_area](Self::set_partial_area).
+    pub fn set_normal_mode(&mut self) -> Result<(), Error> {
+        self.dcs.write_command(EnterNormalMode)?;
+        Ok(())
+    }
+
+    /// Enables or disables idle mode.
+    ///
+    /// In idle mode the color depth of the display is reduced, lowering power consumption while
+    /// keeping the panel refreshing normally.
+    pub fn set_idle(&mut self, idle: bool) -> Result<(), Error> {
+        if idle {
+            self.dcs.write_command(EnterIdleMode)?;
+        } else {
+            self.dcs.write_command(ExitIdleMode)?;
+        }
+        Ok(())
+    }
+}
+
+impl<DI, MODEL, RST> Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+    MODEL::ColorFormat: IntoStorage<Storage = u16>,
+{
+    /// Fills `area` with a single solid `color`.
+    ///
+    /// This sets the address window once and streams the repeated 16-bit color value straight
+    /// over the interface, rather than materializing `area.width * area.height` colors through
+    /// [write_pixels](Self::write_pixels). It is an order of magnitude faster than a per-pixel
+    /// fill for large areas, such as the clears `DrawTarget::clear` performs every frame.
+    pub fn fill_solid(&mut self, area: &Rectangle, color: MODEL::ColorFormat) -> Result<(), Error> {
+        if area.size.width == 0 || area.size.height == 0 {
+            return Ok(());
+        }
+
+        let (x0, y0, x1, y1) = address_window(self.options.window_offset(), area);
+
+        self.dcs.write_command(SetColumnAddress::new(x0, x1))?;
+        self.dcs.write_command(SetPageAddress::new(y0, y1))?;
+        self.dcs.write_command(WriteMemoryStart)?;
+
+        let count = area.size.width as usize * area.size.height as usize;
+        let value = color.into_storage();
+        let mut iter = core::iter::repeat_n(value, count);
+        self.dcs.di.send_data(DataFormat::U16BEIter(&mut iter))?;
+
+        Ok(())
+    }
+}
+
+/// Computes the inclusive `(x0, y0, x1, y1)` address window for `area`, shifted by the panel's
+/// GRAM `window_offset`.
+fn address_window(window_offset: (u16, u16), area: &Rectangle) -> (u16, u16, u16, u16) {
+    let (ox, oy) = window_offset;
+    let x0 = ox + area.top_left.x as u16;
+    let y0 = oy + area.top_left.y as u16;
+    let x1 = x0 + area.size.width as u16 - 1;
+    let y1 = y0 + area.size.height as u16 - 1;
+    (x0, y0, x1, y1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics_core::prelude::{Point, Size};
+
+    #[test]
+    fn address_window_applies_offset_and_is_inclusive() {
+        let area = Rectangle::new(Point::new(2, 3), Size::new(4, 5));
+        assert_eq!(address_window((10, 20), &area), (12, 23, 15, 27));
+    }
+
+    #[test]
+    fn address_window_of_a_single_pixel_has_equal_bounds() {
+        let area = Rectangle::new(Point::new(7, 8), Size::new(1, 1));
+        assert_eq!(address_window((0, 0), &area), (7, 8, 7, 8));
+    }
+}
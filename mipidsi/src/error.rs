@@ -0,0 +1,31 @@
+//! Error module
+
+use display_interface::DisplayError;
+
+/// Error type for DisplayInterfaceError
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Error {
+    /// Error caused by the display interface.
+    DisplayError,
+}
+
+impl From<DisplayError> for Error {
+    fn from(_: DisplayError) -> Self {
+        Self::DisplayError
+    }
+}
+
+/// Error type for the display builder / model initialization.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum InitError<PE> {
+    /// Error caused by the display interface.
+    DisplayError,
+    /// Error caused by the reset pin.
+    Pin(PE),
+}
+
+impl<PE> From<DisplayError> for InitError<PE> {
+    fn from(_: DisplayError) -> Self {
+        Self::DisplayError
+    }
+}
@@ -0,0 +1,58 @@
+//! `embedded-graphics` integration for [Display].
+
+use display_interface::WriteOnlyDataCommand;
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Size},
+    pixelcolor::IntoStorage,
+    primitives::Rectangle,
+    Pixel,
+};
+use embedded_hal::digital::OutputPin;
+
+use crate::{models::Model, Display, Error};
+
+impl<DI, MODEL, RST> OriginDimensions for Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+{
+    fn size(&self) -> Size {
+        let (width, height) = self.options.display_size();
+        Size::new(width.into(), height.into())
+    }
+}
+
+impl<DI, MODEL, RST> DrawTarget for Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+    MODEL::ColorFormat: IntoStorage<Storage = u16>,
+{
+    type Color = MODEL::ColorFormat;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.fill_solid(
+                &Rectangle::new(point, Size::new(1, 1)),
+                color,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        Display::fill_solid(self, area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.bounding_box();
+        Display::fill_solid(self, &area, color)
+    }
+}
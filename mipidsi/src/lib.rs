@@ -0,0 +1,25 @@
+//! This crate provides a generic display driver to connect to TFT displays
+//! that implement the [MIPI Display Command Set](https://www.mipi.org/specifications/display-command-set).
+//!
+//! Uses [display_interface] to talk to the hardware via transports like SPI or I2C.
+
+#![no_std]
+
+mod builder;
+#[cfg(feature = "buffered")]
+pub mod buffer;
+pub mod dcs;
+mod display;
+pub mod error;
+mod graphics;
+pub mod models;
+mod options;
+pub mod size;
+
+pub use builder::Builder;
+#[cfg(feature = "buffered")]
+pub use buffer::{BufferedDisplay, DisplayBuffer};
+pub use display::Display;
+pub use error::Error;
+pub use options::{ModelOptions, Orientation};
+pub use size::DisplaySize;
@@ -0,0 +1,65 @@
+//! Display models.
+
+mod st7796;
+
+pub use st7796::ST7796;
+
+use embedded_graphics_core::prelude::PixelColor;
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+use display_interface::WriteOnlyDataCommand;
+
+use crate::{
+    dcs::{Dcs, SetAddressMode},
+    error::InitError,
+    size::DisplaySize,
+    Error, ModelOptions,
+};
+
+/// Display model.
+pub trait Model {
+    /// The color format used by this display model.
+    type ColorFormat: PixelColor;
+
+    /// The resolution and GRAM offset of the panel, see [DisplaySize].
+    type Size: DisplaySize;
+
+    /// Initializes the display for this model and returns the value of the [SetAddressMode]
+    /// command that was sent during initialization.
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: WriteOnlyDataCommand;
+
+    /// Writes pixel colors to the display, starting from the current address window.
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>;
+
+    /// Returns the default [ModelOptions] for this model.
+    fn default_options() -> ModelOptions;
+
+    /// Performs a hardware reset using the given reset pin.
+    fn hard_reset<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+    ) -> Result<(), InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+    {
+        rst.set_low().map_err(InitError::Pin)?;
+        delay.delay_us(10);
+        rst.set_high().map_err(InitError::Pin)?;
+        Ok(())
+    }
+}
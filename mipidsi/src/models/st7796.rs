@@ -1,25 +1,48 @@
+use core::marker::PhantomData;
+
 use display_interface::{DataFormat, WriteOnlyDataCommand};
 use embedded_graphics_core::{pixelcolor::Rgb565, prelude::IntoStorage};
 use embedded_hal::{delay::DelayNs, digital::OutputPin};
 
 use crate::{
     dcs::{
-        BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat, SetAddressMode,
-        SetDisplayOn, SetInvertMode, SetPixelFormat, SetScrollArea, SoftReset, WriteMemoryStart,
+        write_frame_rate, BitsPerPixel, Dcs, EnterNormalMode, ExitSleepMode, PixelFormat,
+        SetAddressMode, SetDisplayOn, SetInvertMode, SetPixelFormat, SetScrollArea, SoftReset,
+        WriteCtrlDisplay, WriteMemoryStart,
     },
     error::InitError,
+    size::{DisplaySize, DisplaySize320x480},
     Builder, Error, ModelOptions,
 };
 
 use super::Model;
 
-/// ST7796 display in Rgb565 color mode.
+/// ST7796 display in Rgb565 color mode, generic over its [DisplaySize] so the same model code
+/// can drive breakout boards that map the controller's GRAM with a different resolution and/or
+/// offset. Defaults to the common 320x480 panel.
 ///
 /// Interfaces implemented by the [display-interface](https://crates.io/crates/display-interface) are supported.
-pub struct ST7796;
+pub struct ST7796<SIZE = DisplaySize320x480>(PhantomData<SIZE>);
+
+impl<SIZE> ST7796<SIZE> {
+    /// Creates a new ST7796 model marker for the given [DisplaySize].
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<SIZE> Default for ST7796<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-impl Model for ST7796 {
+impl<SIZE> Model for ST7796<SIZE>
+where
+    SIZE: DisplaySize,
+{
     type ColorFormat = Rgb565;
+    type Size = SIZE;
 
     fn init<RST, DELAY, DI>(
         &mut self,
@@ -52,6 +75,10 @@ impl Model for ST7796 {
 
         dcs.write_command(SetInvertMode(options.invert_colors))?;
 
+        if let Some(frame_rate) = options.frame_rate {
+            write_frame_rate(dcs, frame_rate)?;
+        }
+
         let pf = PixelFormat::with_all(BitsPerPixel::from_rgb_color::<Self::ColorFormat>());
         dcs.write_command(SetPixelFormat::new(pf))?;
         delay.delay_us(10_000);
@@ -81,7 +108,11 @@ impl Model for ST7796 {
 
         dcs.write_command(EnterNormalMode)?;
         delay.delay_us(10_000);
-        dcs.write_raw(0x53, &[0x24])?;
+        dcs.write_command(
+            WriteCtrlDisplay::new()
+                .with_brightness_control(true)
+                .with_backlight(true),
+        )?;
         dcs.write_raw(0xF0, &[0x3C])?;
         dcs.write_raw(0xF0, &[0x69])?;
         dcs.write_command(SetDisplayOn)?;
@@ -107,25 +138,27 @@ impl Model for ST7796 {
     }
 
     fn default_options() -> crate::ModelOptions {
-        ModelOptions::with_sizes((320, 480), (320, 480))
+        let size = (Self::Size::WIDTH, Self::Size::HEIGHT);
+        ModelOptions::with_sizes(size, size).with_offset(Self::Size::OFFSET)
     }
 }
 
 // simplified constructor on Display
 
-impl<DI> Builder<DI, ST7796>
+impl<DI, SIZE> Builder<DI, ST7796<SIZE>>
 where
     DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
 {
     /// Creates a new display builder for a ST7796 display in Rgb565 color mode.
     ///
-    /// The default framebuffer size and display size is 240x320 pixels.
-    ///
     /// # Arguments
     ///
     /// * `di` - a [display interface](WriteOnlyDataCommand) for communicating with the display
+    /// * `size` - the panel's [DisplaySize] marker, e.g. [DisplaySize320x480](crate::size::DisplaySize320x480)
+    ///   or a [CustomDisplaySize](crate::size::CustomDisplaySize) for boards with a shifted GRAM window
     ///
-    pub fn st7796(di: DI) -> Self {
-        Self::with_model(di, ST7796)
+    pub fn st7796(di: DI, _size: SIZE) -> Self {
+        Self::with_model(di, ST7796::new())
     }
 }
@@ -0,0 +1,95 @@
+//! Model options and other configuration used to customize [crate::Display] behavior.
+
+use crate::dcs::FrameRate;
+
+/// Display orientation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// 0 degree rotation.
+    #[default]
+    Portrait,
+    /// 180 degree rotation.
+    PortraitInverted,
+    /// 90 degree rotation.
+    Landscape,
+    /// 270 degree rotation.
+    LandscapeInverted,
+}
+
+/// Holds configuration for display.
+#[derive(Copy, Clone, Debug)]
+pub struct ModelOptions {
+    /// Current display orientation (without inverts), see
+    /// [Display::set_orientation](crate::Display::set_orientation)
+    pub(crate) orientation: Orientation,
+    /// Set to make display vertical refresh bottom to top
+    pub(crate) invert_vertical_refresh: bool,
+    /// Set to make display horizontal refresh right to left
+    pub(crate) invert_horizontal_refresh: bool,
+    /// Display color inversion
+    pub(crate) invert_colors: bool,
+    /// Framebuffer size (w, h)
+    framebuffer_size: (u16, u16),
+    /// Display size (w, h)
+    display_size: (u16, u16),
+    /// Offset (x, y) of the visible area within the controller's GRAM, see
+    /// [DisplaySize::OFFSET](crate::size::DisplaySize::OFFSET).
+    offset: (u16, u16),
+    /// Default frame rate override applied during [init](crate::models::Model::init), if any.
+    pub(crate) frame_rate: Option<FrameRate>,
+}
+
+impl ModelOptions {
+    /// Creates model options using given sizes and defaults for everything else.
+    pub fn with_sizes(framebuffer_size: (u16, u16), display_size: (u16, u16)) -> Self {
+        Self {
+            orientation: Orientation::default(),
+            invert_vertical_refresh: false,
+            invert_horizontal_refresh: false,
+            invert_colors: false,
+            framebuffer_size,
+            display_size,
+            offset: (0, 0),
+            frame_rate: None,
+        }
+    }
+
+    /// Sets the GRAM offset of the panel's visible area.
+    pub fn with_offset(mut self, offset: (u16, u16)) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the frame rate applied to the normal, idle and partial mode frame-rate-control
+    /// registers during [init](crate::models::Model::init), overriding the model's defaults.
+    pub fn with_frame_rate(mut self, frame_rate: FrameRate) -> Self {
+        self.frame_rate = Some(frame_rate);
+        self
+    }
+
+    /// Returns the display size based on current orientation and display options.
+    pub fn display_size(&self) -> (u16, u16) {
+        let (w, h) = self.display_size;
+
+        match self.orientation {
+            Orientation::Portrait | Orientation::PortraitInverted => (w, h),
+            Orientation::Landscape | Orientation::LandscapeInverted => (h, w),
+        }
+    }
+
+    /// Returns the framebuffer size as configured.
+    pub fn framebuffer_size(&self) -> (u16, u16) {
+        self.framebuffer_size
+    }
+
+    /// Returns the GRAM offset (x, y) of the panel's visible area, adjusted for the current
+    /// orientation.
+    pub fn window_offset(&self) -> (u16, u16) {
+        let (ox, oy) = self.offset;
+
+        match self.orientation {
+            Orientation::Portrait | Orientation::PortraitInverted => (ox, oy),
+            Orientation::Landscape | Orientation::LandscapeInverted => (oy, ox),
+        }
+    }
+}
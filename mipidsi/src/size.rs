@@ -0,0 +1,59 @@
+//! Type-level display sizes, used to parameterize [Model](crate::models::Model) implementations
+//! that are shared across panel variants with different resolutions or GRAM offsets.
+
+/// A display's resolution and the offset of its active area within the controller's GRAM.
+///
+/// Implementors are zero-sized marker types, selected at the type level (e.g. via
+/// [Builder::st7796](crate::Builder::st7796)) so the resolution and offset are known at compile
+/// time and can be baked into every [SetColumnAddress](crate::dcs::SetColumnAddress) /
+/// [SetPageAddress](crate::dcs::SetPageAddress) computation without runtime cost.
+pub trait DisplaySize {
+    /// Width in pixels.
+    const WIDTH: u16;
+    /// Height in pixels.
+    const HEIGHT: u16;
+    /// Offset (x, y) of the visible area within the controller's GRAM.
+    const OFFSET: (u16, u16) = (0, 0);
+}
+
+macro_rules! display_size {
+    ($name:ident => $width:literal, $height:literal) => {
+        #[doc = concat!(stringify!($width), "x", stringify!($height), " display size.")]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+        pub struct $name;
+
+        impl DisplaySize for $name {
+            const WIDTH: u16 = $width;
+            const HEIGHT: u16 = $height;
+        }
+    };
+}
+
+display_size!(DisplaySize240x320 => 240, 320);
+display_size!(DisplaySize320x480 => 320, 480);
+display_size!(DisplaySize170x320 => 170, 320);
+
+/// Generic escape hatch for panels with a non-standard resolution and/or GRAM offset, such as
+/// breakout boards that map the same controller with a shifted active area.
+///
+/// ```
+/// use mipidsi::size::CustomDisplaySize;
+///
+/// // A 240x280 panel whose active area starts at GRAM offset (0, 20).
+/// type MyPanel = CustomDisplaySize<240, 280, 0, 20>;
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct CustomDisplaySize<
+    const WIDTH: u16,
+    const HEIGHT: u16,
+    const OX: u16 = 0,
+    const OY: u16 = 0,
+>;
+
+impl<const WIDTH: u16, const HEIGHT: u16, const OX: u16, const OY: u16> DisplaySize
+    for CustomDisplaySize<WIDTH, HEIGHT, OX, OY>
+{
+    const WIDTH: u16 = WIDTH;
+    const HEIGHT: u16 = HEIGHT;
+    const OFFSET: (u16, u16) = (OX, OY);
+}